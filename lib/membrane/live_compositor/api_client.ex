@@ -26,13 +26,13 @@ defmodule Membrane.LiveCompositor.ApiClient do
     {:post, "/api/start", %{}} |> send_request(lc_port)
   end
 
-  @spec get_status(:inet.port_number()) :: request_result()
-  def get_status(lc_port) do
-    {:get, "/status", nil} |> send_request(lc_port)
+  @spec get_status(:inet.port_number(), Keyword.t()) :: request_result()
+  def get_status(lc_port, req_opts \\ []) do
+    {:get, "/status", nil} |> send_request(lc_port, req_opts)
   end
 
-  @spec send_request(request(), :inet.port_number()) :: request_result()
-  def send_request(request, lc_port) do
+  @spec send_request(request(), :inet.port_number(), Keyword.t()) :: request_result()
+  def send_request(request, lc_port, req_opts \\ []) do
     {method, route, body} = request
     url = lc_url(lc_port, route)
 
@@ -40,8 +40,8 @@ defmodule Membrane.LiveCompositor.ApiClient do
 
     response =
       case method do
-        :post -> Req.post(url, json: body)
-        :get -> Req.get(url)
+        :post -> Req.post(url, [json: body] ++ req_opts)
+        :get -> Req.get(url, req_opts)
       end
 
     handle_request_result(response)
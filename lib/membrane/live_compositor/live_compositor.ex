@@ -199,6 +199,26 @@ defmodule Membrane.LiveCompositor do
                 """,
                 default: :start_locally
               ],
+              server_startup_timeout: [
+                spec: Membrane.Time.t(),
+                description: """
+                Maximum time the LiveCompositor bin waits for a locally started LC server to
+                respond on a single port before trying the next one. Increase it on machines
+                where the server starts slowly (e.g. GPU initialization on cloud VMs).
+
+                The limit applies to each port separately. If `api_port` is a range, ports are
+                tried one after another, so the whole startup can take up to the number of
+                tried ports multiplied by this value. Must be at least 1 ms. If the server doesn't
+                respond on any port, the bin raises an error.
+
+                Previously, failed status checks were retried with backoff, so the bin could wait
+                for minutes on a single port. That wait is now strictly bounded by this option,
+                so servers that need more than 30 seconds to start require a higher value.
+
+                Ignored when `server_setup` is `:already_started`.
+                """,
+                default: Membrane.Time.seconds(30)
+              ],
               init_requests: [
                 spec: list(Request.t()),
                 description: """
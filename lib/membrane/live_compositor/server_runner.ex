@@ -6,6 +6,8 @@ defmodule Membrane.LiveCompositor.ServerRunner do
   alias Membrane.LiveCompositor
   alias Membrane.LiveCompositor.ApiClient
 
+  @startup_poll_interval_ms 100
+
   @spec ensure_server_started(LiveCompositor.t()) ::
           {:ok, :inet.port_number(), pid()} | {:error, err :: String.t()}
   def ensure_server_started(opt) do
@@ -37,12 +39,10 @@ defmodule Membrane.LiveCompositor.ServerRunner do
               """
           end
 
-        {:ok, lc_port, server_pid} = start_server(path, opt.api_port, env, instance_id)
-        {:ok, lc_port, server_pid}
+        start_server!(path, opt.api_port, env, instance_id, opt.server_startup_timeout)
 
       {:start_locally, path} ->
-        {:ok, lc_port, server_pid} = start_server(path, opt.api_port, env, instance_id)
-        {:ok, lc_port, server_pid}
+        start_server!(path, opt.api_port, env, instance_id, opt.server_startup_timeout)
 
       :already_started ->
         case opt.api_port do
@@ -55,14 +55,48 @@ defmodule Membrane.LiveCompositor.ServerRunner do
     end
   end
 
+  @spec start_server!(
+          String.t(),
+          :inet.port_number() | LiveCompositor.port_range(),
+          map(),
+          String.t(),
+          Membrane.Time.t()
+        ) :: {:ok, :inet.port_number(), pid()}
+  defp start_server!(bin_path, port_or_port_range, env, instance_id, startup_timeout) do
+    unless is_integer(startup_timeout) and
+             Membrane.Time.as_milliseconds(startup_timeout, :round) > 0 do
+      raise """
+      server_startup_timeout has to be a Membrane.Time.t() of at least 1 ms, got: \
+      #{inspect(startup_timeout)}.
+      """
+    end
+
+    case start_server(bin_path, port_or_port_range, env, instance_id, startup_timeout) do
+      {:ok, lc_port, server_pid} ->
+        {:ok, lc_port, server_pid}
+
+      {:error, err} ->
+        raise """
+        #{err} Tried ports: #{format_ports(port_or_port_range)}, waiting up to \
+        server_startup_timeout (#{Membrane.Time.as_milliseconds(startup_timeout, :round)} ms) \
+        on each port. If the server needs more time to start on this machine, increase \
+        server_startup_timeout.
+        """
+    end
+  end
+
+  defp format_ports({lower_bound, upper_bound}), do: "#{lower_bound}..#{upper_bound}"
+  defp format_ports(exact), do: "#{exact}"
+
   @spec start_server(
           String.t(),
           :inet.port_number() | LiveCompositor.port_range(),
           map(),
-          String.t()
+          String.t(),
+          Membrane.Time.t()
         ) ::
           {:ok, :inet.port_number(), pid()} | {:error, err :: String.t()}
-  defp start_server(bin_path, port_or_port_range, env, instance_id) do
+  defp start_server(bin_path, port_or_port_range, env, instance_id, startup_timeout) do
     {port_lower_bound, port_upper_bound} =
       case port_or_port_range do
         {start, endd} -> {start, endd}
@@ -77,23 +111,33 @@ defmodule Membrane.LiveCompositor.ServerRunner do
     |> Enum.shuffle()
     |> Enum.reduce_while(
       {:error, "Failed to start a LiveCompositor server on any of the ports."},
-      fn port, err -> try_starting_on_port(port, err, env, bin_path, instance_id) end
+      fn port, err ->
+        try_starting_on_port(port, err, env, bin_path, instance_id, startup_timeout)
+      end
     )
   end
 
-  @spec try_starting_on_port(:inet.port_number(), String.t(), map(), String.t(), String.t()) ::
-          {:halt, {:ok, :inet.port_number()}} | {:cont, err :: String.t()}
-  defp try_starting_on_port(port, err, env, bin_path, instance_id) do
+  @spec try_starting_on_port(
+          :inet.port_number(),
+          String.t(),
+          map(),
+          String.t(),
+          String.t(),
+          Membrane.Time.t()
+        ) ::
+          {:halt, {:ok, :inet.port_number(), pid()}} | {:cont, err :: String.t()}
+  defp try_starting_on_port(port, err, env, bin_path, instance_id, startup_timeout) do
     Membrane.Logger.debug("Trying to launch LiveCompositor on port: #{port}")
 
-    case start_on_port(port, env, bin_path, instance_id) do
+    case start_on_port(port, env, bin_path, instance_id, startup_timeout) do
       {:ok, pid} -> {:halt, {:ok, port, pid}}
       :error -> {:cont, err}
     end
   end
 
-  @spec start_on_port(:inet.port_number(), map(), String.t(), String.t()) :: {:ok, pid()} | :error
-  defp start_on_port(lc_port, env, bin_path, instance_id) do
+  @spec start_on_port(:inet.port_number(), map(), String.t(), String.t(), Membrane.Time.t()) ::
+          {:ok, pid()} | :error
+  defp start_on_port(lc_port, env, bin_path, instance_id, startup_timeout) do
     pid =
       spawn(fn ->
         bin_path
@@ -110,36 +154,52 @@ defmodule Membrane.LiveCompositor.ServerRunner do
         )
       end)
 
-    case wait_for_lc_startup(lc_port, pid, instance_id) do
+    case wait_for_lc_startup(lc_port, pid, instance_id, startup_timeout) do
       :started ->
         {:ok, pid}
 
       :not_started ->
+        Membrane.Logger.debug("LiveCompositor failed to start on port: #{lc_port}")
         Process.exit(pid, :kill)
         :error
     end
   end
 
-  @spec wait_for_lc_startup(:inet.port_number(), pid(), String.t()) :: :started | :not_started
-  defp wait_for_lc_startup(lc_port, pid, instance_id) do
-    0..300
-    |> Enum.reduce_while(:not_started, fn _i, _acc ->
-      Process.sleep(100)
-
-      with {:is_alive, true} <- {:is_alive, Process.alive?(pid)},
-           {:ok, response} <- ApiClient.get_status(lc_port) do
-        if response.body["instance_id"] == instance_id do
-          {:halt, :started}
-        else
-          {:halt, :not_started}
-        end
-      else
-        {:is_alive, false} ->
-          {:halt, :not_started}
+  @spec wait_for_lc_startup(:inet.port_number(), pid(), String.t(), Membrane.Time.t()) ::
+          :started | :not_started
+  defp wait_for_lc_startup(lc_port, pid, instance_id, startup_timeout) do
+    deadline =
+      System.monotonic_time(:millisecond) +
+        Membrane.Time.as_milliseconds(startup_timeout, :round)
+
+    poll_lc_status(lc_port, pid, instance_id, deadline)
+  end
 
-        {:error, _reason} ->
-          {:cont, :not_started}
+  @spec poll_lc_status(:inet.port_number(), pid(), String.t(), integer()) ::
+          :started | :not_started
+  defp poll_lc_status(lc_port, pid, instance_id, deadline) do
+    remaining_ms = deadline - System.monotonic_time(:millisecond)
+
+    with {:time_left, true} <- {:time_left, remaining_ms > 0},
+         {:is_alive, true} <- {:is_alive, Process.alive?(pid)},
+         {:ok, response} <-
+           ApiClient.get_status(lc_port, retry: false, receive_timeout: remaining_ms) do
+      if response.body["instance_id"] == instance_id do
+        :started
+      else
+        :not_started
       end
-    end)
+    else
+      {:time_left, false} ->
+        :not_started
+
+      {:is_alive, false} ->
+        :not_started
+
+      {:error, _reason} ->
+        remaining_ms = deadline - System.monotonic_time(:millisecond)
+        Process.sleep(min(@startup_poll_interval_ms, max(remaining_ms, 0)))
+        poll_lc_status(lc_port, pid, instance_id, deadline)
+    end
   end
 end